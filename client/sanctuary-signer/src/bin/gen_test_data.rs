@@ -1,56 +1,47 @@
 //! Generate deterministic test data for Solidity integration testing
 
-use pqcrypto_dilithium::dilithium2::{
-    detached_sign, keypair, verify_detached_signature,
-    DetachedSignature, PublicKey, SecretKey,
-};
-use pqcrypto_traits::sign::{
-    DetachedSignature as DetachedSignatureTrait, PublicKey as PublicKeyTrait,
-    SecretKey as SecretKeyTrait,
-};
+use sanctuary_signer::{SanctuaryWallet, SecurityLevel};
+
+fn generate_for_level(level: SecurityLevel, label: &str) {
+    println!("\n=== {} FIXTURES ===\n", label);
+
+    let wallet = SanctuaryWallet::new(level);
+    let pk_bytes = wallet.public_key();
 
-fn main() {
-    println!("=== SANCTUARY PROTOCOL - Solidity Integration Data ===\n");
-    
-    // Create wallet
-    let (pk, sk) = keypair();
-    let pk_bytes = pk.as_bytes();
-    let sk_bytes = sk.as_bytes();
-    
     println!("📦 PUBLIC KEY (for mockPublicKey in Solidity):");
     println!("Size: {} bytes", pk_bytes.len());
     println!("\nmockPublicKey = hex\"{}\";", hex::encode(pk_bytes));
-    
+
     // Create a deterministic message (userOpHash simulation)
     let user_op_hash = b"sanctuary_test_user_operation_hash_v1";
-    
-    // Sign
-    let signature = detached_sign(user_op_hash, &sk);
-    let sig_bytes = signature.as_bytes();
-    
+
+    let signature = wallet.sign_transaction(user_op_hash).expect("Signing failed");
+
     println!("\n\n📝 SIGNATURE (for mockSignature in Solidity):");
-    println!("Size: {} bytes", sig_bytes.len());
-    println!("\nmockSignature = hex\"{}\";", hex::encode(sig_bytes));
-    
-    // Verify it works
-    let sig_check = DetachedSignature::from_bytes(sig_bytes).unwrap();
-    let pk_check = PublicKey::from_bytes(pk_bytes).unwrap();
-    let is_valid = verify_detached_signature(&sig_check, user_op_hash, &pk_check).is_ok();
-    
+    println!("Size: {} bytes", signature.as_bytes().len());
+    println!("\nmockSignature = hex\"{}\";", hex::encode(signature.as_bytes()));
+
+    let is_valid = SanctuaryWallet::verify_transaction(
+        &wallet.public_key_bytes(),
+        user_op_hash,
+        &signature,
+    )
+    .expect("Verification failed");
+
     println!("\n\n✅ VERIFICATION CHECK:");
     println!("Message: {:?}", String::from_utf8_lossy(user_op_hash));
     println!("Signature Valid: {}", is_valid);
-    
-    // Also print keccak256 hash for comparison (simple hash for now)
-    let mut pk_hash = [0u8; 32];
-    for (i, chunk) in pk_bytes.chunks(32).enumerate() {
-        for (j, &byte) in chunk.iter().enumerate() {
-            if j < 32 {
-                pk_hash[j] ^= byte.wrapping_add(i as u8);
-            }
-        }
-    }
-    println!("\nPublic Key Hash (simple): 0x{}", hex::encode(&pk_hash));
-    
+
+    println!("\nPublic Key Hash (keccak256): 0x{}", hex::encode(wallet.public_key_hash()));
+    println!("Public Key Address (ownerImg): 0x{}", hex::encode(wallet.public_key_address()));
+}
+
+fn main() {
+    println!("=== SANCTUARY PROTOCOL - Solidity Integration Data ===");
+
+    generate_for_level(SecurityLevel::Dilithium2, "DILITHIUM2");
+    generate_for_level(SecurityLevel::Dilithium3, "DILITHIUM3");
+    generate_for_level(SecurityLevel::Dilithium5, "DILITHIUM5");
+
     println!("\n=== COPY THE hex\"...\" VALUES ABOVE TO SanctuaryVault.t.sol ===");
 }
@@ -3,23 +3,259 @@
 //! This library provides quantum-safe cryptographic signing capabilities
 //! for the Sanctuary Protocol on Ethereum Layer-2.
 
-use pqcrypto_dilithium::dilithium2::{
-    detached_sign, keypair, verify_detached_signature,
-    DetachedSignature, PublicKey, SecretKey,
-};
+use pqcrypto_dilithium::{dilithium2, dilithium3, dilithium5};
 use pqcrypto_traits::sign::{
     DetachedSignature as DetachedSignatureTrait, PublicKey as PublicKeyTrait,
     SecretKey as SecretKeyTrait,
 };
+use aes::cipher::{KeyIvInit, StreamCipher};
+use bip39::Mnemonic;
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, SigningKey, VerifyingKey};
+use rand::RngCore;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore as ChaChaRngCore, SeedableRng};
+use scrypt::Params as ScryptParams;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use subtle::ConstantTimeEq;
+use tiny_keccak::{Hasher, Keccak};
+
+type Aes128Ctr64BE = ctr::Ctr64BE<aes::Aes128>;
+
+/// Keccak256 atas data arbitrary, dipakai bersama oleh wallet dan data generator
+/// supaya commitment (`ownerImg`, address) selalu cocok dengan `keccak256` versi Solidity.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+thread_local! {
+    /// RNG deterministik untuk keygen yang diturunkan dari mnemonic. `None` berarti
+    /// pakai randomness OS seperti biasa.
+    static DETERMINISTIC_RNG: RefCell<Option<ChaCha20Rng>> = const { RefCell::new(None) };
+}
+
+/// Override sumber randomness lewat hook resmi `getrandom` (`register_custom_getrandom!`),
+/// BUKAN dengan redefine symbol C `randombytes()` milik PQClean secara langsung --
+/// `pqcrypto-internals` (dependency transitif `pqcrypto-dilithium`) sudah menyediakan
+/// symbol itu sendiri lewat `getrandom::fill`, jadi mendefinisikan ulang symbol yang sama
+/// akan bentrok (duplicate symbol) saat linking.
+///
+/// PENTING: `pqcrypto-internals` pada `pqcrypto-dilithium 0.5` mem-pin `getrandom` di
+/// major `0.3`. Supaya registrasi custom-backend di bawah ini benar-benar dipakai oleh
+/// panggilan `getrandom::fill` milik `pqcrypto-internals` -- bukan nyasar ke instance
+/// crate yang berbeda di dependency graph, yang membuat keygen tidak deterministik sama
+/// sekali -- `Cargo.toml` WAJIB deklarasi dependency langsung:
+/// `getrandom = { version = "0.3", features = ["custom"] }`
+/// supaya Cargo unify ke satu instance `getrandom` yang sama persis dengan yang dipakai
+/// `pqcrypto-internals`. Tanpa pin major yang sama ini, registrasi di atas diam-diam
+/// tidak pernah dikonsultasikan oleh jalur keygen Dilithium.
+///
+/// Reference implementation Dilithium hanya memanggil `randombytes()` sekali untuk seed
+/// 32-byte-nya lalu mengekspansi sisanya lewat SHAKE256, jadi mengganti sumber randomness
+/// ini selama satu panggilan `keypair()` menghasilkan keypair yang valid dan bisa
+/// direproduksi dari seed yang sama.
+fn deterministic_or_os_random(buf: &mut [u8]) -> Result<(), getrandom::Error> {
+    DETERMINISTIC_RNG.with(|rng| match rng.borrow_mut().as_mut() {
+        Some(r) => ChaChaRngCore::fill_bytes(r, buf),
+        None => os_random(buf),
+    });
+    Ok(())
+}
+
+/// Randomness OS langsung dari `/dev/urandom`, sengaja tidak lewat `getrandom::getrandom`
+/// lagi supaya tidak rekursi balik ke `deterministic_or_os_random` setelah di-register.
+fn os_random(buf: &mut [u8]) {
+    use std::io::Read;
+    std::fs::File::open("/dev/urandom")
+        .and_then(|mut f| f.read_exact(buf))
+        .expect("OS RNG gagal");
+}
+
+getrandom::register_custom_getrandom!(deterministic_or_os_random);
+
+/// Level keamanan NIST yang didukung, mengikuti tiga parameter set CRYSTALS-Dilithium
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecurityLevel {
+    Dilithium2,
+    Dilithium3,
+    Dilithium5,
+}
+
+impl SecurityLevel {
+    /// Ukuran public key untuk level ini, dalam bytes
+    pub fn pk_size(&self) -> usize {
+        match self {
+            SecurityLevel::Dilithium2 => dilithium2::public_key_bytes(),
+            SecurityLevel::Dilithium3 => dilithium3::public_key_bytes(),
+            SecurityLevel::Dilithium5 => dilithium5::public_key_bytes(),
+        }
+    }
+
+    /// Ukuran secret key untuk level ini, dalam bytes
+    pub fn sk_size(&self) -> usize {
+        match self {
+            SecurityLevel::Dilithium2 => dilithium2::secret_key_bytes(),
+            SecurityLevel::Dilithium3 => dilithium3::secret_key_bytes(),
+            SecurityLevel::Dilithium5 => dilithium5::secret_key_bytes(),
+        }
+    }
+
+    /// Ukuran detached signature untuk level ini, dalam bytes
+    pub fn sig_size(&self) -> usize {
+        match self {
+            SecurityLevel::Dilithium2 => dilithium2::signature_bytes(),
+            SecurityLevel::Dilithium3 => dilithium3::signature_bytes(),
+            SecurityLevel::Dilithium5 => dilithium5::signature_bytes(),
+        }
+    }
+}
+
+/// Generate keypair Dilithium baru pada level yang diminta, lewat sumber randomness yang
+/// aktif: OS randomness, atau RNG deterministik saat dipanggil dari `keypair_from_seed`
+fn generate_keypair(level: SecurityLevel) -> (Vec<u8>, Vec<u8>) {
+    match level {
+        SecurityLevel::Dilithium2 => {
+            let (pk, sk) = dilithium2::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        SecurityLevel::Dilithium3 => {
+            let (pk, sk) = dilithium3::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        SecurityLevel::Dilithium5 => {
+            let (pk, sk) = dilithium5::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+    }
+}
+
+/// Generate keypair Dilithium secara deterministik dari seed 32-byte, lewat custom
+/// backend `getrandom` di atas (`deterministic_or_os_random`).
+fn keypair_from_seed(level: SecurityLevel, seed: [u8; 32]) -> (Vec<u8>, Vec<u8>) {
+    DETERMINISTIC_RNG.with(|rng| *rng.borrow_mut() = Some(ChaCha20Rng::from_seed(seed)));
+    let result = generate_keypair(level);
+    DETERMINISTIC_RNG.with(|rng| *rng.borrow_mut() = None);
+    result
+}
 
 /// Ukuran signature Dilithium Level 2 dalam bytes
 pub const DILITHIUM2_SIG_SIZE: usize = 2420;
-/// Ukuran public key Dilithium Level 2 dalam bytes  
+/// Ukuran public key Dilithium Level 2 dalam bytes
 pub const DILITHIUM2_PK_SIZE: usize = 1312;
 /// Ukuran secret key Dilithium Level 2 dalam bytes
 pub const DILITHIUM2_SK_SIZE: usize = 2560;
 
+/// Newtype untuk public key Dilithium yang sudah divalidasi panjangnya (sesuai level-nya)
+/// saat konstruksi, jadi buffer salah ukuran gagal di boundary, bukan nyasar
+/// jauh ke dalam API sebelum ketahuan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DilithiumPublicKeyBytes {
+    level: SecurityLevel,
+    bytes: Vec<u8>,
+}
+
+impl DilithiumPublicKeyBytes {
+    pub fn from_bytes(level: SecurityLevel, bytes: &[u8]) -> Result<Self, SanctuaryError> {
+        if bytes.len() != level.pk_size() {
+            return Err(SanctuaryError::InvalidPublicKeySize);
+        }
+        Ok(Self { level, bytes: bytes.to_vec() })
+    }
+
+    pub fn level(&self) -> SecurityLevel {
+        self.level
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Public key Dilithium yang sudah dimaterialisasi, satu varian per level keamanan.
+/// L3/L5 di-box supaya varian terbesar tidak memaksa semua varian lain ikut
+/// menanggung ukuran stack-nya (`clippy::large_enum_variant`).
+enum DilithiumPublicKey {
+    L2(dilithium2::PublicKey),
+    L3(Box<dilithium3::PublicKey>),
+    L5(Box<dilithium5::PublicKey>),
+}
+
+impl TryFrom<&DilithiumPublicKeyBytes> for DilithiumPublicKey {
+    type Error = SanctuaryError;
+
+    fn try_from(value: &DilithiumPublicKeyBytes) -> Result<Self, Self::Error> {
+        let err = SanctuaryError::KeyDeserializationFailed;
+        match value.level {
+            SecurityLevel::Dilithium2 => {
+                dilithium2::PublicKey::from_bytes(&value.bytes).map(DilithiumPublicKey::L2).map_err(|_| err)
+            }
+            SecurityLevel::Dilithium3 => dilithium3::PublicKey::from_bytes(&value.bytes)
+                .map(|pk| DilithiumPublicKey::L3(Box::new(pk)))
+                .map_err(|_| err),
+            SecurityLevel::Dilithium5 => dilithium5::PublicKey::from_bytes(&value.bytes)
+                .map(|pk| DilithiumPublicKey::L5(Box::new(pk)))
+                .map_err(|_| err),
+        }
+    }
+}
+
+/// Newtype untuk signature Dilithium yang sudah divalidasi panjangnya (sesuai level-nya)
+/// saat konstruksi; signature yang sebenarnya baru dibentuk saat dibutuhkan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DilithiumSignatureBytes {
+    level: SecurityLevel,
+    bytes: Vec<u8>,
+}
+
+impl DilithiumSignatureBytes {
+    pub fn from_bytes(level: SecurityLevel, bytes: &[u8]) -> Result<Self, SanctuaryError> {
+        if bytes.len() != level.sig_size() {
+            return Err(SanctuaryError::InvalidSignatureSize);
+        }
+        Ok(Self { level, bytes: bytes.to_vec() })
+    }
+
+    pub fn level(&self) -> SecurityLevel {
+        self.level
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Detached signature Dilithium yang sudah dimaterialisasi, satu varian per level keamanan.
+/// L3/L5 di-box supaya varian terbesar tidak memaksa semua varian lain ikut
+/// menanggung ukuran stack-nya (`clippy::large_enum_variant`).
+enum DilithiumDetachedSignature {
+    L2(dilithium2::DetachedSignature),
+    L3(Box<dilithium3::DetachedSignature>),
+    L5(Box<dilithium5::DetachedSignature>),
+}
+
+impl TryFrom<&DilithiumSignatureBytes> for DilithiumDetachedSignature {
+    type Error = SanctuaryError;
+
+    fn try_from(value: &DilithiumSignatureBytes) -> Result<Self, Self::Error> {
+        let err = SanctuaryError::KeyDeserializationFailed;
+        match value.level {
+            SecurityLevel::Dilithium2 => dilithium2::DetachedSignature::from_bytes(&value.bytes)
+                .map(DilithiumDetachedSignature::L2)
+                .map_err(|_| err),
+            SecurityLevel::Dilithium3 => dilithium3::DetachedSignature::from_bytes(&value.bytes)
+                .map(|sig| DilithiumDetachedSignature::L3(Box::new(sig)))
+                .map_err(|_| err),
+            SecurityLevel::Dilithium5 => dilithium5::DetachedSignature::from_bytes(&value.bytes)
+                .map(|sig| DilithiumDetachedSignature::L5(Box::new(sig)))
+                .map_err(|_| err),
+        }
+    }
+}
+
 /// Error types untuk SanctuaryWallet
 #[derive(Debug, Clone, PartialEq)]
 pub enum SanctuaryError {
@@ -28,23 +264,202 @@ pub enum SanctuaryError {
     InvalidSignatureSize,
     SignatureVerificationFailed,
     KeyDeserializationFailed,
+    InvalidPassword,
+    KeystoreIoError(String),
+    KeystoreDeserializationFailed,
+    InvalidMnemonic,
+    InvalidHexEncoding,
+    SecurityLevelMismatch,
+    MissingEcdsaKey,
+    MissingSignature,
+    EcdsaRecoveryFailed,
+    LegacyAddressMismatch,
+}
+
+/// Signature ECDSA secp256k1 dalam format Ethereum (r ‖ s ‖ v, 65 byte)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcdsaSignatureBytes([u8; 65]);
+
+impl EcdsaSignatureBytes {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SanctuaryError> {
+        if bytes.len() != 65 {
+            return Err(SanctuaryError::InvalidSignatureSize);
+        }
+        let mut buf = [0u8; 65];
+        buf.copy_from_slice(bytes);
+        Ok(Self(buf))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Turunkan Ethereum address dari secp256k1 verifying key: keccak256(uncompressed[1..])[12..32]
+fn ecdsa_address_from_verifying_key(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let uncompressed = verifying_key.to_encoded_point(false);
+    let hash = keccak256(&uncompressed.as_bytes()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    address
+}
+
+/// `ecrecover`: pulihkan Ethereum address penanda tangan dari digest + signature Ethereum-style
+pub fn ecrecover(digest: &[u8; 32], signature: &EcdsaSignatureBytes) -> Result<[u8; 20], SanctuaryError> {
+    let sig = EcdsaSignature::from_slice(&signature.0[..64])
+        .map_err(|_| SanctuaryError::EcdsaRecoveryFailed)?;
+    let recovery_byte = signature.0[64];
+    let recovery_id = RecoveryId::from_byte(if recovery_byte >= 27 { recovery_byte - 27 } else { recovery_byte })
+        .ok_or(SanctuaryError::EcdsaRecoveryFailed)?;
+
+    let verifying_key = VerifyingKey::recover_from_prehash(digest, &sig, recovery_id)
+        .map_err(|_| SanctuaryError::EcdsaRecoveryFailed)?;
+
+    Ok(ecdsa_address_from_verifying_key(&verifying_key))
+}
+
+/// Sign digest dengan secp256k1, menghasilkan signature format Ethereum (r ‖ s ‖ v)
+fn ecdsa_sign(ecdsa_sk: &[u8; 32], digest: &[u8; 32]) -> Result<EcdsaSignatureBytes, SanctuaryError> {
+    let signing_key =
+        SigningKey::from_bytes(ecdsa_sk.into()).map_err(|_| SanctuaryError::EcdsaRecoveryFailed)?;
+    let (sig, recovery_id): (EcdsaSignature, RecoveryId) = signing_key
+        .sign_prehash_recoverable(digest)
+        .map_err(|_| SanctuaryError::EcdsaRecoveryFailed)?;
+
+    let mut bytes = [0u8; 65];
+    bytes[..64].copy_from_slice(&sig.to_bytes());
+    bytes[64] = recovery_id.to_byte() + 27;
+    Ok(EcdsaSignatureBytes(bytes))
+}
+
+/// Left-pad byte slice (maks 32 byte) menjadi word 32-byte ala ABI encoding
+fn left_pad_32(bytes: &[u8]) -> [u8; 32] {
+    let mut padded = [0u8; 32];
+    let start = 32 - bytes.len();
+    padded[start..].copy_from_slice(bytes);
+    padded
+}
+
+/// Versi format keystore, mengikuti Web3 Secret Storage Definition
+const KEYSTORE_VERSION: u32 = 3;
+/// scrypt `N` - cost parameter (2^13), cukup kuat tanpa membuat save/load terasa lambat
+const SCRYPT_LOG_N: u8 = 13;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const SCRYPT_DKLEN: usize = 32;
+
+/// Parameter cipher AES-128-CTR yang dipakai untuk mengenkripsi secret key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystoreCipherParams {
+    iv: String,
+}
+
+/// Parameter KDF (scrypt) yang dipakai untuk menurunkan kunci enkripsi dari password
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystoreKdfParams {
+    dklen: usize,
+    n: u32,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    ciphertext: String,
+    cipherparams: KeystoreCipherParams,
+    kdf: String,
+    kdfparams: KeystoreKdfParams,
+    mac: String,
+}
+
+/// Struktur keystore JSON versi Web3 Secret Storage, menyimpan secret key Dilithium
+/// yang terenkripsi. Public key disimpan apa adanya karena memang bukan rahasia.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SanctuaryKeystore {
+    version: u32,
+    id: String,
+    /// Nama level keamanan Dilithium ("dilithium2"/"dilithium3"/"dilithium5")
+    level: String,
+    pubkey: String,
+    crypto: KeystoreCrypto,
+}
+
+impl SecurityLevel {
+    fn as_keystore_str(&self) -> &'static str {
+        match self {
+            SecurityLevel::Dilithium2 => "dilithium2",
+            SecurityLevel::Dilithium3 => "dilithium3",
+            SecurityLevel::Dilithium5 => "dilithium5",
+        }
+    }
+
+    fn from_keystore_str(s: &str) -> Result<Self, SanctuaryError> {
+        match s {
+            "dilithium2" => Ok(SecurityLevel::Dilithium2),
+            "dilithium3" => Ok(SecurityLevel::Dilithium3),
+            "dilithium5" => Ok(SecurityLevel::Dilithium5),
+            _ => Err(SanctuaryError::KeystoreDeserializationFailed),
+        }
+    }
 }
 
 /// SanctuaryWallet - Quantum-resistant wallet menggunakan CRYSTALS-Dilithium
 #[derive(Clone)]
 pub struct SanctuaryWallet {
+    level: SecurityLevel,
     pk: Vec<u8>,
     sk: Vec<u8>,
+    /// Secret key secp256k1 opsional untuk mode hybrid (migrasi dari akun Ethereum klasik)
+    ecdsa_sk: Option<[u8; 32]>,
+    /// Address Ethereum legacy yang sudah terbukti (lewat `bind_legacy_address`) mengklaim
+    /// public key Dilithium wallet ini sebagai penerus pasca-kuantum
+    legacy_address: Option<[u8; 20]>,
 }
 
 impl SanctuaryWallet {
-    /// Generate wallet baru dengan keypair Dilithium yang quantum-safe
-    pub fn new() -> Self {
-        let (pk, sk) = keypair();
-        SanctuaryWallet {
-            pk: pk.as_bytes().to_vec(),
-            sk: sk.as_bytes().to_vec(),
-        }
+    /// Generate wallet baru dengan keypair Dilithium yang quantum-safe, pada level
+    /// keamanan yang dipilih
+    pub fn new(level: SecurityLevel) -> Self {
+        let (pk, sk) = generate_keypair(level);
+        SanctuaryWallet { level, pk, sk, ecdsa_sk: None, legacy_address: None }
+    }
+
+    /// Generate mnemonic BIP-39 baru (12/15/18/21/24 kata) untuk backup wallet
+    pub fn generate_mnemonic(word_count: usize) -> Result<String, SanctuaryError> {
+        let mnemonic =
+            Mnemonic::generate(word_count).map_err(|_| SanctuaryError::InvalidMnemonic)?;
+        Ok(mnemonic.to_string())
+    }
+
+    /// Pulihkan wallet secara deterministik dari mnemonic BIP-39.
+    ///
+    /// Seed 64-byte diturunkan lewat PBKDF2-HMAC-SHA512 standar BIP-39 (2048 iterasi,
+    /// salt `"mnemonic" + passphrase`), lalu dipakai untuk menyeed CSPRNG yang mendorong
+    /// keygen Dilithium sehingga deterministik. `index` opsional memungkinkan satu
+    /// mnemonic menurunkan banyak wallet (mirip derivation path HD wallet).
+    pub fn from_mnemonic(
+        level: SecurityLevel,
+        phrase: &str,
+        passphrase: &str,
+        index: Option<u32>,
+    ) -> Result<Self, SanctuaryError> {
+        let mnemonic = Mnemonic::parse(phrase).map_err(|_| SanctuaryError::InvalidMnemonic)?;
+        let bip39_seed = mnemonic.to_seed(passphrase);
+
+        let mut seed_input = Vec::with_capacity(bip39_seed.len() + 4);
+        seed_input.extend_from_slice(&bip39_seed);
+        seed_input.extend_from_slice(&index.unwrap_or(0).to_be_bytes());
+        let wallet_seed = keccak256(&seed_input);
+
+        let (pk, sk) = keypair_from_seed(level, wallet_seed);
+        Ok(SanctuaryWallet { level, pk, sk, ecdsa_sk: None, legacy_address: None })
+    }
+
+    /// Level keamanan Dilithium yang dipakai wallet ini
+    pub fn security_level(&self) -> SecurityLevel {
+        self.level
     }
 
     /// Mendapatkan public key dalam format bytes
@@ -59,86 +474,348 @@ impl SanctuaryWallet {
 
     /// Mendapatkan hash dari public key (untuk ownerImg di Smart Contract)
     pub fn public_key_hash(&self) -> [u8; 32] {
-        // Simple hash menggunakan keccak256-like approach
-        // Untuk production, gunakan proper keccak256
-        let mut hash = [0u8; 32];
-        for (i, chunk) in self.pk.chunks(32).enumerate() {
-            for (j, &byte) in chunk.iter().enumerate() {
-                if j < 32 {
-                    hash[j] ^= byte.wrapping_add(i as u8);
-                }
-            }
-        }
-        hash
+        keccak256(&self.pk)
     }
 
-    /// Sign pesan/transaksi dengan Dilithium signature
-    /// Returns: Detached signature (2420 bytes untuk Level 2)
-    pub fn sign_transaction(&self, message: &[u8]) -> Result<Vec<u8>, SanctuaryError> {
-        // Reconstruct secret key from bytes
-        let sk = SecretKey::from_bytes(&self.sk)
-            .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
-        
-        let signature = detached_sign(message, &sk);
-        Ok(signature.as_bytes().to_vec())
+    /// Turunkan Ethereum-style address (20 byte rendah) dari hash public key.
+    /// Dipakai sebagai commitment yang bisa direproduksi oleh contract Solidity.
+    pub fn public_key_address(&self) -> [u8; 20] {
+        let hash = self.public_key_hash();
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..32]);
+        address
+    }
+
+    /// Mendapatkan public key sebagai `DilithiumPublicKeyBytes` yang tervalidasi,
+    /// siap dipakai langsung sebagai argumen `verify_transaction`
+    pub fn public_key_bytes(&self) -> DilithiumPublicKeyBytes {
+        DilithiumPublicKeyBytes::from_bytes(self.level, &self.pk)
+            .expect("public key wallet selalu valid")
     }
 
-    /// Verify signature (simulasi apa yang akan dilakukan Smart Contract)
+    /// Sign pesan/transaksi dengan Dilithium signature, pada level keamanan wallet ini
+    pub fn sign_transaction(&self, message: &[u8]) -> Result<DilithiumSignatureBytes, SanctuaryError> {
+        let sig_bytes = match self.level {
+            SecurityLevel::Dilithium2 => {
+                let sk = dilithium2::SecretKey::from_bytes(&self.sk)
+                    .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
+                dilithium2::detached_sign(message, &sk).as_bytes().to_vec()
+            }
+            SecurityLevel::Dilithium3 => {
+                let sk = dilithium3::SecretKey::from_bytes(&self.sk)
+                    .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
+                dilithium3::detached_sign(message, &sk).as_bytes().to_vec()
+            }
+            SecurityLevel::Dilithium5 => {
+                let sk = dilithium5::SecretKey::from_bytes(&self.sk)
+                    .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
+                dilithium5::detached_sign(message, &sk).as_bytes().to_vec()
+            }
+        };
+        DilithiumSignatureBytes::from_bytes(self.level, &sig_bytes)
+    }
+
+    /// Verify signature (simulasi apa yang akan dilakukan Smart Contract). Level keamanan
+    /// diambil dari `pk_bytes` dan divalidasi harus sama dengan level `signature_bytes`.
     pub fn verify_transaction(
-        pk_bytes: &[u8],
+        pk_bytes: &DilithiumPublicKeyBytes,
         message: &[u8],
-        signature_bytes: &[u8],
+        signature_bytes: &DilithiumSignatureBytes,
     ) -> Result<bool, SanctuaryError> {
-        // Validate sizes
-        if pk_bytes.len() != DILITHIUM2_PK_SIZE {
-            return Err(SanctuaryError::InvalidPublicKeySize);
+        if pk_bytes.level() != signature_bytes.level() {
+            return Err(SanctuaryError::SecurityLevelMismatch);
         }
-        if signature_bytes.len() != DILITHIUM2_SIG_SIZE {
-            return Err(SanctuaryError::InvalidSignatureSize);
+
+        let pk: DilithiumPublicKey = pk_bytes.try_into()?;
+        let sig: DilithiumDetachedSignature = signature_bytes.try_into()?;
+
+        let result = match (pk, sig) {
+            (DilithiumPublicKey::L2(pk), DilithiumDetachedSignature::L2(sig)) => {
+                dilithium2::verify_detached_signature(&sig, message, &pk)
+            }
+            (DilithiumPublicKey::L3(pk), DilithiumDetachedSignature::L3(sig)) => {
+                dilithium3::verify_detached_signature(&sig, message, &pk)
+            }
+            (DilithiumPublicKey::L5(pk), DilithiumDetachedSignature::L5(sig)) => {
+                dilithium5::verify_detached_signature(&sig, message, &pk)
+            }
+            _ => return Err(SanctuaryError::SecurityLevelMismatch),
+        };
+
+        Ok(result.is_ok())
+    }
+
+    /// Turunkan 32-byte encryption key dari password + salt menggunakan scrypt
+    fn derive_keystore_key(password: &str, salt: &[u8]) -> Result<[u8; 32], SanctuaryError> {
+        let params = ScryptParams::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, SCRYPT_DKLEN)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        let mut derived_key = [0u8; 32];
+        scrypt::scrypt(password.as_bytes(), salt, &params, &mut derived_key)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        Ok(derived_key)
+    }
+
+    /// Simpan secret key sebagai keystore JSON terenkripsi (format Web3 Secret Storage).
+    /// Memakai scrypt untuk KDF, AES-128-CTR untuk cipher, dan keccak256-MAC untuk
+    /// mendeteksi password yang salah sebelum mencoba dekripsi.
+    pub fn save_keystore(&self, path: &str, password: &str) -> Result<(), SanctuaryError> {
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        let mut id_bytes = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut iv);
+        rand::thread_rng().fill_bytes(&mut id_bytes);
+
+        let derived_key = Self::derive_keystore_key(password, &salt)?;
+
+        let mut ciphertext = self.sk.clone();
+        let mut cipher = Aes128Ctr64BE::new(derived_key[..16].into(), (&iv).into());
+        cipher.apply_keystream(&mut ciphertext);
+
+        let mut mac_input = Vec::with_capacity(16 + ciphertext.len());
+        mac_input.extend_from_slice(&derived_key[16..32]);
+        mac_input.extend_from_slice(&ciphertext);
+        let mac = keccak256(&mac_input);
+
+        let keystore = SanctuaryKeystore {
+            version: KEYSTORE_VERSION,
+            id: format_keystore_id(&id_bytes),
+            level: self.level.as_keystore_str().to_string(),
+            pubkey: hex::encode(&self.pk),
+            crypto: KeystoreCrypto {
+                cipher: "aes-128-ctr".to_string(),
+                ciphertext: hex::encode(&ciphertext),
+                cipherparams: KeystoreCipherParams { iv: hex::encode(iv) },
+                kdf: "scrypt".to_string(),
+                kdfparams: KeystoreKdfParams {
+                    dklen: SCRYPT_DKLEN,
+                    n: 1u32 << SCRYPT_LOG_N,
+                    r: SCRYPT_R,
+                    p: SCRYPT_P,
+                    salt: hex::encode(salt),
+                },
+                mac: hex::encode(mac),
+            },
+        };
+
+        let json = serde_json::to_string_pretty(&keystore)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        std::fs::write(path, json).map_err(|e| SanctuaryError::KeystoreIoError(e.to_string()))
+    }
+
+    /// Muat wallet dari keystore JSON terenkripsi. MAC diverifikasi sebelum dekripsi
+    /// sehingga password yang salah gagal dengan bersih lewat `InvalidPassword`.
+    pub fn load_keystore(path: &str, password: &str) -> Result<Self, SanctuaryError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| SanctuaryError::KeystoreIoError(e.to_string()))?;
+        let keystore: SanctuaryKeystore =
+            serde_json::from_str(&json).map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+
+        let salt = hex::decode(&keystore.crypto.kdfparams.salt)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        let iv = hex::decode(&keystore.crypto.cipherparams.iv)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        let mut ciphertext = hex::decode(&keystore.crypto.ciphertext)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        let expected_mac = hex::decode(&keystore.crypto.mac)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+
+        let derived_key = Self::derive_keystore_key(password, &salt)?;
+
+        let mut mac_input = Vec::with_capacity(16 + ciphertext.len());
+        mac_input.extend_from_slice(&derived_key[16..32]);
+        mac_input.extend_from_slice(&ciphertext);
+        // Constant-time compare: ini gerbang verifikasi password, jadi tidak boleh
+        // bocor lewat timing berapa banyak byte awal MAC yang sudah cocok.
+        let mac_matches: bool =
+            keccak256(&mac_input).as_slice().ct_eq(expected_mac.as_slice()).into();
+        if !mac_matches {
+            return Err(SanctuaryError::InvalidPassword);
         }
 
-        // Reconstruct public key and signature
-        let pk = PublicKey::from_bytes(pk_bytes)
-            .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
-        let sig = DetachedSignature::from_bytes(signature_bytes)
-            .map_err(|_| SanctuaryError::KeyDeserializationFailed)?;
+        let mut cipher = Aes128Ctr64BE::new(derived_key[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut ciphertext);
 
-        // Verify
-        match verify_detached_signature(&sig, message, &pk) {
-            Ok(()) => Ok(true),
-            Err(_) => Ok(false),
+        let pk = hex::decode(&keystore.pubkey)
+            .map_err(|_| SanctuaryError::KeystoreDeserializationFailed)?;
+        let level = SecurityLevel::from_keystore_str(&keystore.level)?;
+
+        Ok(SanctuaryWallet { level, pk, sk: ciphertext, ecdsa_sk: None, legacy_address: None })
+    }
+
+    /// Generate wallet hybrid: keypair Dilithium seperti biasa, ditambah keypair
+    /// secp256k1 klasik untuk menjembatani migrasi dari akun Ethereum yang sudah ada.
+    pub fn new_hybrid(level: SecurityLevel) -> Self {
+        let mut wallet = Self::new(level);
+        let mut ecdsa_sk = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut ecdsa_sk);
+        wallet.ecdsa_sk = Some(ecdsa_sk);
+        wallet
+    }
+
+    /// Address Ethereum classic dari secp256k1 key wallet ini, kalau ada (mode hybrid)
+    pub fn ecdsa_address(&self) -> Result<[u8; 20], SanctuaryError> {
+        let ecdsa_sk = self.ecdsa_sk.ok_or(SanctuaryError::MissingEcdsaKey)?;
+        let signing_key =
+            SigningKey::from_bytes((&ecdsa_sk).into()).map_err(|_| SanctuaryError::EcdsaRecoveryFailed)?;
+        Ok(ecdsa_address_from_verifying_key(signing_key.verifying_key()))
+    }
+
+    /// Verifikasi klaim migrasi: `expected_address` menandatangani `message` lewat ECDSA.
+    /// Kalau `ecrecover` atas signature tersebut cocok dengan `expected_address`, public key
+    /// Dilithium wallet ini dicatat sebagai penerus pasca-kuantum yang sah untuk address itu.
+    pub fn bind_legacy_address(
+        &mut self,
+        message: &[u8],
+        signature: &EcdsaSignatureBytes,
+        expected_address: [u8; 20],
+    ) -> Result<(), SanctuaryError> {
+        let digest = keccak256(message);
+        let recovered = ecrecover(&digest, signature)?;
+        if recovered != expected_address {
+            return Err(SanctuaryError::LegacyAddressMismatch);
+        }
+        self.legacy_address = Some(expected_address);
+        Ok(())
+    }
+
+    /// Address Ethereum legacy yang sudah terikat lewat `bind_legacy_address`, kalau ada
+    pub fn legacy_address(&self) -> Option<[u8; 20]> {
+        self.legacy_address
+    }
+
+    /// Sign `message` dengan ECDSA (secp256k1) dan Dilithium sekaligus, atas digest yang sama,
+    /// supaya contract bisa terima signature klasik sekarang dan signature quantum-safe
+    /// setelah cutover.
+    pub fn sign_hybrid(
+        &self,
+        message: &[u8],
+    ) -> Result<(EcdsaSignatureBytes, DilithiumSignatureBytes), SanctuaryError> {
+        let ecdsa_sk = self.ecdsa_sk.ok_or(SanctuaryError::MissingEcdsaKey)?;
+        let digest = keccak256(message);
+
+        let ecdsa_signature = ecdsa_sign(&ecdsa_sk, &digest)?;
+        let dilithium_signature = self.sign_transaction(&digest)?;
+
+        Ok((ecdsa_signature, dilithium_signature))
+    }
+
+    /// Verifikasi signature hybrid: terima signature ECDSA klasik ATAU signature Dilithium
+    /// quantum-safe, mana saja yang disediakan, keduanya atas digest keccak256 yang sama
+    /// persis yang ditandatangani `sign_hybrid`. Ini yang memungkinkan contract menerima
+    /// signature klasik sebelum cutover dan signature quantum-safe sesudahnya.
+    pub fn verify_hybrid(
+        ecdsa_address: [u8; 20],
+        dilithium_pk: &DilithiumPublicKeyBytes,
+        message: &[u8],
+        ecdsa_signature: Option<&EcdsaSignatureBytes>,
+        dilithium_signature: Option<&DilithiumSignatureBytes>,
+    ) -> Result<bool, SanctuaryError> {
+        let digest = keccak256(message);
+
+        if let Some(signature) = ecdsa_signature {
+            let recovered = ecrecover(&digest, signature)?;
+            return Ok(recovered == ecdsa_address);
         }
+        if let Some(signature) = dilithium_signature {
+            return Self::verify_transaction(dilithium_pk, &digest, signature);
+        }
+        Err(SanctuaryError::MissingSignature)
     }
 }
 
+/// Format 16 byte acak sebagai string mirip UUID v4 untuk field `id` keystore
+fn format_keystore_id(bytes: &[u8; 16]) -> String {
+    format!(
+        "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        bytes[0], bytes[1], bytes[2], bytes[3],
+        bytes[4], bytes[5],
+        bytes[6], bytes[7],
+        bytes[8], bytes[9],
+        bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+    )
+}
+
 impl Default for SanctuaryWallet {
     fn default() -> Self {
-        Self::new()
+        Self::new(SecurityLevel::Dilithium2)
+    }
+}
+
+/// Domain EIP-712 yang mengikat signature ke contract dan chain tertentu, supaya
+/// signature yang sama tidak bisa di-replay di contract atau chain lain.
+#[derive(Debug, Clone)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub chain_id: u64,
+    pub verifying_contract: [u8; 20],
+}
+
+impl Eip712Domain {
+    /// `domainSeparator = keccak256(typeHash ‖ keccak256(name) ‖ chainId ‖ verifyingContract)`
+    pub fn separator(&self) -> [u8; 32] {
+        let type_hash =
+            keccak256(b"EIP712Domain(string name,uint256 chainId,address verifyingContract)");
+        let name_hash = keccak256(self.name.as_bytes());
+
+        let mut encoded = Vec::with_capacity(32 * 4);
+        encoded.extend_from_slice(&type_hash);
+        encoded.extend_from_slice(&name_hash);
+        encoded.extend_from_slice(&left_pad_32(&self.chain_id.to_be_bytes()));
+        encoded.extend_from_slice(&left_pad_32(&self.verifying_contract));
+        keccak256(&encoded)
     }
 }
 
 /// Struktur untuk serialisasi transaksi yang akan dikirim ke blockchain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SanctuaryTransaction {
-    /// Target address (hex encoded)
+    /// Target address (hex encoded, diawali "0x")
     pub to: String,
     /// Value dalam wei
     pub value: u128,
-    /// Calldata (hex encoded)
+    /// Calldata (hex encoded, diawali "0x")
     pub data: String,
     /// Nonce untuk replay protection
     pub nonce: u64,
 }
 
 impl SanctuaryTransaction {
-    /// Encode transaksi menjadi bytes untuk signing
-    pub fn encode(&self) -> Vec<u8> {
-        let mut encoded = Vec::new();
-        encoded.extend_from_slice(self.to.as_bytes());
-        encoded.extend_from_slice(&self.value.to_be_bytes());
-        encoded.extend_from_slice(self.data.as_bytes());
-        encoded.extend_from_slice(&self.nonce.to_be_bytes());
-        encoded
+    fn parse_to(&self) -> Result<[u8; 20], SanctuaryError> {
+        let bytes = hex::decode(self.to.trim_start_matches("0x"))
+            .map_err(|_| SanctuaryError::InvalidHexEncoding)?;
+        bytes
+            .try_into()
+            .map_err(|_| SanctuaryError::InvalidHexEncoding)
+    }
+
+    fn parse_data(&self) -> Result<Vec<u8>, SanctuaryError> {
+        hex::decode(self.data.trim_start_matches("0x")).map_err(|_| SanctuaryError::InvalidHexEncoding)
+    }
+
+    /// `structHash = keccak256(typeHash ‖ to(32) ‖ value(32) ‖ keccak256(data) ‖ nonce(32))`
+    pub fn struct_hash(&self) -> Result<[u8; 32], SanctuaryError> {
+        let type_hash = keccak256(b"Transaction(address to,uint256 value,bytes data,uint256 nonce)");
+        let to = self.parse_to()?;
+        let data = self.parse_data()?;
+
+        let mut encoded = Vec::with_capacity(32 * 5);
+        encoded.extend_from_slice(&type_hash);
+        encoded.extend_from_slice(&left_pad_32(&to));
+        encoded.extend_from_slice(&left_pad_32(&self.value.to_be_bytes()));
+        encoded.extend_from_slice(&keccak256(&data));
+        encoded.extend_from_slice(&left_pad_32(&self.nonce.to_be_bytes()));
+        Ok(keccak256(&encoded))
+    }
+
+    /// Digest EIP-712 final: `keccak256(0x1901 ‖ domainSeparator ‖ structHash)`.
+    /// Inilah yang di-sign oleh wallet, bukan encoding mentah lagi.
+    pub fn eip712_digest(&self, domain: &Eip712Domain) -> Result<[u8; 32], SanctuaryError> {
+        let mut encoded = Vec::with_capacity(2 + 32 + 32);
+        encoded.extend_from_slice(&[0x19, 0x01]);
+        encoded.extend_from_slice(&domain.separator());
+        encoded.extend_from_slice(&self.struct_hash()?);
+        Ok(keccak256(&encoded))
     }
 }
 
@@ -148,7 +825,7 @@ mod tests {
 
     #[test]
     fn test_wallet_creation() {
-        let wallet = SanctuaryWallet::new();
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
         
         println!("=== Sanctuary Wallet Created ===");
         println!("Public Key Size: {} bytes", wallet.public_key().len());
@@ -159,22 +836,22 @@ mod tests {
 
     #[test]
     fn test_sign_and_verify() {
-        let wallet = SanctuaryWallet::new();
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
         let message = b"Transfer 100 ETH to Alice";
 
         // Sign
         let signature = wallet.sign_transaction(message).expect("Signing failed");
-        
+
         println!("=== Quantum-Safe Transaction Signed ===");
         println!("Message: {:?}", String::from_utf8_lossy(message));
-        println!("Signature Size: {} bytes", signature.len());
-        println!("Signature (first 64 chars): {}...", &hex::encode(&signature)[..64]);
+        println!("Signature Size: {} bytes", signature.as_bytes().len());
+        println!("Signature (first 64 chars): {}...", &hex::encode(signature.as_bytes())[..64]);
 
-        assert_eq!(signature.len(), DILITHIUM2_SIG_SIZE);
+        assert_eq!(signature.as_bytes().len(), DILITHIUM2_SIG_SIZE);
 
         // Verify
         let is_valid = SanctuaryWallet::verify_transaction(
-            wallet.public_key(),
+            &wallet.public_key_bytes(),
             message,
             &signature,
         ).expect("Verification failed");
@@ -185,16 +862,19 @@ mod tests {
 
     #[test]
     fn test_invalid_signature_rejected() {
-        let wallet = SanctuaryWallet::new();
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
         let message = b"Transfer 100 ETH to Alice";
         
-        let mut signature = wallet.sign_transaction(message).expect("Signing failed");
-        
+        let signature = wallet.sign_transaction(message).expect("Signing failed");
+
         // Corrupt the signature
-        signature[0] ^= 0xFF;
-        
+        let mut corrupted = signature.as_bytes().to_vec();
+        corrupted[0] ^= 0xFF;
+        let signature = DilithiumSignatureBytes::from_bytes(SecurityLevel::Dilithium2, &corrupted)
+            .expect("valid length");
+
         let is_valid = SanctuaryWallet::verify_transaction(
-            wallet.public_key(),
+            &wallet.public_key_bytes(),
             message,
             &signature,
         ).expect("Verification failed");
@@ -205,14 +885,14 @@ mod tests {
 
     #[test]
     fn test_wrong_message_rejected() {
-        let wallet = SanctuaryWallet::new();
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
         let message = b"Transfer 100 ETH to Alice";
         let wrong_message = b"Transfer 100 ETH to Bob";
 
         let signature = wallet.sign_transaction(message).expect("Signing failed");
 
         let is_valid = SanctuaryWallet::verify_transaction(
-            wallet.public_key(),
+            &wallet.public_key_bytes(),
             wrong_message,
             &signature,
         ).expect("Verification failed");
@@ -226,7 +906,7 @@ mod tests {
         println!("\n=== SANCTUARY PROTOCOL - Full Transaction Flow ===\n");
         
         // 1. Create wallet
-        let wallet = SanctuaryWallet::new();
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
         println!("1. Wallet Created");
         println!("   Public Key Hash: 0x{}", hex::encode(wallet.public_key_hash()));
 
@@ -241,23 +921,163 @@ mod tests {
         println!("   To: {}", tx.to);
         println!("   Value: {} wei", tx.value);
 
-        // 3. Sign transaction
-        let tx_bytes = tx.encode();
-        let signature = wallet.sign_transaction(&tx_bytes).expect("Signing failed");
+        // 3. Sign transaction (EIP-712 digest, reproducible by the verifying contract)
+        let domain = Eip712Domain {
+            name: "SanctuaryProtocol".to_string(),
+            chain_id: 1,
+            verifying_contract: [0u8; 20],
+        };
+        let digest = tx.eip712_digest(&domain).expect("Encoding failed");
+        let signature = wallet.sign_transaction(&digest).expect("Signing failed");
         println!("3. Transaction Signed");
-        println!("   Signature Size: {} bytes", signature.len());
+        println!("   Signature Size: {} bytes", signature.as_bytes().len());
 
         // 4. Verify (simulating Smart Contract)
         let is_valid = SanctuaryWallet::verify_transaction(
-            wallet.public_key(),
-            &tx_bytes,
+            &wallet.public_key_bytes(),
+            &digest,
             &signature,
         ).expect("Verification failed");
         println!("4. Signature Verified: {}", is_valid);
 
         println!("\n=== Transaction Ready for L2 Submission ===");
-        println!("   Signature Hex: {}...", &hex::encode(&signature)[..100]);
+        println!("   Signature Hex: {}...", &hex::encode(signature.as_bytes())[..100]);
         
         assert!(is_valid);
     }
+
+    #[test]
+    fn test_higher_security_levels() {
+        for level in [SecurityLevel::Dilithium3, SecurityLevel::Dilithium5] {
+            let wallet = SanctuaryWallet::new(level);
+            let message = b"Transfer 100 ETH to Alice";
+
+            assert_eq!(wallet.public_key().len(), level.pk_size());
+
+            let signature = wallet.sign_transaction(message).expect("Signing failed");
+            assert_eq!(signature.as_bytes().len(), level.sig_size());
+
+            let is_valid = SanctuaryWallet::verify_transaction(
+                &wallet.public_key_bytes(),
+                message,
+                &signature,
+            ).expect("Verification failed");
+            assert!(is_valid);
+        }
+    }
+
+    #[test]
+    fn test_from_mnemonic_is_deterministic() {
+        let phrase = SanctuaryWallet::generate_mnemonic(12).expect("Mnemonic generation failed");
+
+        let wallet_a = SanctuaryWallet::from_mnemonic(SecurityLevel::Dilithium2, &phrase, "", Some(0))
+            .expect("Recovery failed");
+        let wallet_b = SanctuaryWallet::from_mnemonic(SecurityLevel::Dilithium2, &phrase, "", Some(0))
+            .expect("Recovery failed");
+
+        assert_eq!(wallet_a.public_key(), wallet_b.public_key());
+
+        let wallet_c = SanctuaryWallet::from_mnemonic(SecurityLevel::Dilithium2, &phrase, "", Some(1))
+            .expect("Recovery failed");
+        assert_ne!(wallet_a.public_key(), wallet_c.public_key());
+    }
+
+    #[test]
+    fn test_hybrid_sign_and_verify() {
+        let wallet = SanctuaryWallet::new_hybrid(SecurityLevel::Dilithium2);
+        let message = b"migrate to quantum-safe identity";
+
+        let (ecdsa_signature, dilithium_signature) =
+            wallet.sign_hybrid(message).expect("Hybrid signing failed");
+
+        let ecdsa_address = wallet.ecdsa_address().expect("Missing ECDSA key");
+        let dilithium_pk = wallet.public_key_bytes();
+
+        let valid_ecdsa = SanctuaryWallet::verify_hybrid(
+            ecdsa_address,
+            &dilithium_pk,
+            message,
+            Some(&ecdsa_signature),
+            None,
+        )
+        .expect("ECDSA verification failed");
+        assert!(valid_ecdsa);
+
+        let valid_dilithium = SanctuaryWallet::verify_hybrid(
+            ecdsa_address,
+            &dilithium_pk,
+            message,
+            None,
+            Some(&dilithium_signature),
+        )
+        .expect("Dilithium verification failed");
+        assert!(valid_dilithium);
+    }
+
+    #[test]
+    fn test_bind_legacy_address() {
+        let mut wallet = SanctuaryWallet::new_hybrid(SecurityLevel::Dilithium2);
+        let claim_message = b"I authorize this Dilithium key as my post-quantum successor";
+        let ecdsa_address = wallet.ecdsa_address().expect("Missing ECDSA key");
+
+        let digest = keccak256(claim_message);
+        let ecdsa_sk = wallet.ecdsa_sk.expect("Missing ECDSA key");
+        let signature = ecdsa_sign(&ecdsa_sk, &digest).expect("Signing failed");
+
+        wallet
+            .bind_legacy_address(claim_message, &signature, ecdsa_address)
+            .expect("Legacy address binding failed");
+        assert_eq!(wallet.legacy_address(), Some(ecdsa_address));
+
+        let wrong_address = [0xAAu8; 20];
+        let mut other_wallet = SanctuaryWallet::new_hybrid(SecurityLevel::Dilithium2);
+        let err = other_wallet
+            .bind_legacy_address(claim_message, &signature, wrong_address)
+            .unwrap_err();
+        assert_eq!(err, SanctuaryError::LegacyAddressMismatch);
+    }
+
+    #[test]
+    fn test_public_key_bytes_rejects_wrong_length() {
+        let err = DilithiumPublicKeyBytes::from_bytes(SecurityLevel::Dilithium2, &[0u8; 10])
+            .unwrap_err();
+        assert_eq!(err, SanctuaryError::InvalidPublicKeySize);
+    }
+
+    #[test]
+    fn test_signature_bytes_rejects_wrong_length() {
+        let err = DilithiumSignatureBytes::from_bytes(SecurityLevel::Dilithium2, &[0u8; 10])
+            .unwrap_err();
+        assert_eq!(err, SanctuaryError::InvalidSignatureSize);
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
+        let path = std::env::temp_dir().join("sanctuary_test_keystore_round_trip.json");
+        let path = path.to_str().expect("Path not valid UTF-8");
+
+        wallet.save_keystore(path, "correct horse battery staple").expect("Save failed");
+        let loaded = SanctuaryWallet::load_keystore(path, "correct horse battery staple")
+            .expect("Load failed");
+
+        assert_eq!(loaded.security_level(), wallet.security_level());
+        assert_eq!(loaded.public_key(), wallet.public_key());
+        assert_eq!(loaded.sk, wallet.sk);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_keystore_wrong_password_rejected() {
+        let wallet = SanctuaryWallet::new(SecurityLevel::Dilithium2);
+        let path = std::env::temp_dir().join("sanctuary_test_keystore_wrong_password.json");
+        let path = path.to_str().expect("Path not valid UTF-8");
+
+        wallet.save_keystore(path, "correct horse battery staple").expect("Save failed");
+        let result = SanctuaryWallet::load_keystore(path, "wrong password");
+        assert!(matches!(result, Err(SanctuaryError::InvalidPassword)));
+
+        std::fs::remove_file(path).ok();
+    }
 }